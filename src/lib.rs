@@ -26,19 +26,49 @@
 //! ```
 //! # Notes about safety
 //! This crate internally uses `unsafe` to achieve its functionality.
-//! However, it provides a safe interface.
-//! It takes the following precautions for safety:
-//! 1. Pointer arithmetic is never explicitly performed. A pointer pointing to
-//! the end of the first slice is calculated using safe API's.
-//! 2. Equality comparisons between pointers, although undefined behaviour in C in
-//! cases where the pointers originate from different objects, can be considered
-//! to be safe in Rust. This is ensured by the fact that the standard library
-//! provides a safe function `core::ptr::eq` to compares pointers.
-//! 3. `unsafe` is only used to call `core::slice::from_raw_parts` to create a new
-//! slice after the check that the input slices are adjacent in memory.
+//! Most of its interface is nonetheless fully safe:
+//! 1. `rejoin`/`try_rejoin`/`checked_rejoin`, `rejoin_all`/`try_rejoin_all` and
+//! `rejoin_either`/`try_rejoin_either` (and their `_mut`/`str` variants) only ever join two
+//! slices when the pointer immediately past `self` equals the start of `other`. A pointer
+//! pointing to the end of the first slice is calculated using safe APIs. `rejoin_either`
+//! compares it with `core::ptr::eq`; `checked_rejoin` (which `rejoin`, `try_rejoin` and
+//! `rejoin_all` go through) compares the pointers' addresses as integers, and uses their
+//! ordering only to choose which [`RejoinError`] to report. Both comparisons are safe in Rust
+//! even for pointers that originate from different objects. `unsafe` is only used to call
+//! `core::slice::from_raw_parts` once the equality confirms the two slices are genuinely
+//! adjacent. Slices of a zero-sized element type are never joined by these functions.
+//! 2. `rejoin_overlapping` and `rejoin_bridge` (and their `try_` variants) cannot offer the same
+//! guarantee: merging a gap or an overlap requires comparing pointer *addresses*, not just
+//! their equality, and two slices from unrelated allocations can satisfy such a comparison by
+//! pure coincidence. Because of this, those functions are themselves `unsafe fn`: the caller
+//! must uphold that `self` and `other` point into the same allocated object.
 
 #![no_std]
 
+/// The reason two slices could not be rejoined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejoinError {
+    /// There is a gap of `bytes` bytes between the end of `self` and the start of `other`.
+    Gap { bytes: usize },
+    /// `other` starts `bytes` bytes before the end of `self`.
+    Overlap { bytes: usize },
+    /// `other` comes before `self` in memory, rather than after it.
+    WrongOrder,
+    /// The slices' pointers aren't comparable, so no ordering could be established.
+    Disjoint,
+}
+
+impl core::fmt::Display for RejoinError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RejoinError::Gap { bytes } => write!(f, "a gap of {bytes} byte(s) between the slices"),
+            RejoinError::Overlap { bytes } => write!(f, "an overlap of {bytes} byte(s) between the slices"),
+            RejoinError::WrongOrder => write!(f, "the slices are in the wrong order"),
+            RejoinError::Disjoint => write!(f, "the slices' pointers aren't comparable"),
+        }
+    }
+}
+
 pub trait SliceExt {
     /// Joins two slices that are adjacent in memory into one slice.
     /// # Panics
@@ -57,35 +87,285 @@ pub trait SliceExt {
     /// Joins two mutable slices that are adjacent in memory into one slice.
     /// Returns None in the case the slices aren't adjacent.
     fn try_rejoin_mut<'r>(&'r mut self, other: &'r mut Self) -> Option<&'r mut Self>;
+
+    /// Joins two slices that are adjacent in memory into one slice.
+    /// Returns a [`RejoinError`] describing why the slices aren't adjacent.
+    fn checked_rejoin<'r>(&'r self, other: &'r Self) -> Result<&'r Self, RejoinError>;
+
+    /// Joins two mutable slices that are adjacent in memory into one slice.
+    /// Returns a [`RejoinError`] describing why the slices aren't adjacent.
+    fn checked_rejoin_mut<'r>(&'r mut self, other: &'r mut Self) -> Result<&'r mut Self, RejoinError>;
+
+    /// Joins an iterator of slices that are adjacent in memory, in order, into one slice.
+    /// # Panics
+    /// Panics in the case the iterator is empty, or any two consecutive pieces aren't adjacent.
+    fn rejoin_all<'r, I: IntoIterator<Item = &'r Self>>(iter: I) -> &'r Self
+    where
+        Self: 'r;
+
+    /// Joins an iterator of mutable slices that are adjacent in memory, in order, into one slice.
+    /// # Panics
+    /// Panics in the case the iterator is empty, or any two consecutive pieces aren't adjacent.
+    fn rejoin_all_mut<'r, I: IntoIterator<Item = &'r mut Self>>(iter: I) -> &'r mut Self
+    where
+        Self: 'r;
+
+    /// Joins an iterator of slices that are adjacent in memory, in order, into one slice.
+    /// Returns None in the case the iterator is empty, or any two consecutive pieces aren't adjacent.
+    fn try_rejoin_all<'r, I: IntoIterator<Item = &'r Self>>(iter: I) -> Option<&'r Self>
+    where
+        Self: 'r;
+
+    /// Joins an iterator of mutable slices that are adjacent in memory, in order, into one slice.
+    /// Returns None in the case the iterator is empty, or any two consecutive pieces aren't adjacent.
+    fn try_rejoin_all_mut<'r, I: IntoIterator<Item = &'r mut Self>>(iter: I) -> Option<&'r mut Self>
+    where
+        Self: 'r;
+
+    /// Joins two slices that are adjacent in memory into one slice, regardless of which one
+    /// comes first. Useful for pieces coming out of a reversed split, where the traversal
+    /// order is not known ahead of time.
+    /// # Panics
+    /// Panics in the case neither ordering of the slices is adjacent.
+    fn rejoin_either<'r>(&'r self, other: &'r Self) -> &'r Self;
+
+    /// Joins two mutable slices that are adjacent in memory into one slice, regardless of which
+    /// one comes first.
+    /// # Panics
+    /// Panics in the case neither ordering of the slices is adjacent.
+    fn rejoin_either_mut<'r>(&'r mut self, other: &'r mut Self) -> &'r mut Self;
+
+    /// Joins two slices that are adjacent in memory into one slice, regardless of which one
+    /// comes first. Returns None in the case neither ordering of the slices is adjacent.
+    fn try_rejoin_either<'r>(&'r self, other: &'r Self) -> Option<&'r Self>;
+
+    /// Joins two mutable slices that are adjacent in memory into one slice, regardless of which
+    /// one comes first. Returns None in the case neither ordering of the slices is adjacent.
+    fn try_rejoin_either_mut<'r>(&'r mut self, other: &'r mut Self) -> Option<&'r mut Self>;
+
+    /// Joins two slices that are adjacent in memory, or that overlap, into their covering slice.
+    /// Useful for reassembling a run of overlapping slices, such as the ones produced by
+    /// `[T]::windows`. `other` must start at or after `self` and leave no gap.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object. This can't be checked from
+    /// the slices alone: the adjacency check here only orders pointer addresses, and two slices
+    /// from unrelated allocations can satisfy that ordering by coincidence.
+    /// # Panics
+    /// Panics in the case `other` starts before `self`, or there is a gap between them.
+    unsafe fn rejoin_overlapping<'r>(&'r self, other: &'r Self) -> &'r Self;
+
+    /// Joins two slices that are adjacent in memory, or that overlap, into their covering slice.
+    /// Returns None in the case `other` starts before `self`, or there is a gap between them.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object. This can't be checked from
+    /// the slices alone: the adjacency check here only orders pointer addresses, and two slices
+    /// from unrelated allocations can satisfy that ordering by coincidence.
+    unsafe fn try_rejoin_overlapping<'r>(&'r self, other: &'r Self) -> Option<&'r Self>;
+
+    /// Recovers the full span `self.start..other.end` of two non-overlapping slices from the
+    /// same parent slice, including any untouched middle region between them. Useful after
+    /// splitting a slice into `[head, middle, tail]` and discarding `middle`: `head.rejoin_bridge(tail)`
+    /// brings back the original contiguous span.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object, with `other` starting at or
+    /// after the end of `self`. This can't be checked from the slices alone: two slices from
+    /// unrelated allocations can satisfy the ordering check by coincidence, and the bytes between
+    /// them are not actually part of either input slice.
+    /// # Panics
+    /// Panics in the case `other` starts before the end of `self`.
+    unsafe fn rejoin_bridge<'r>(&'r self, other: &'r Self) -> &'r Self;
+
+    /// Recovers the full span `self.start..other.end` of two non-overlapping slices from the
+    /// same parent slice, including any untouched middle region between them.
+    /// Returns None in the case `other` starts before the end of `self`.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object, with `other` starting at or
+    /// after the end of `self`. This can't be checked from the slices alone: two slices from
+    /// unrelated allocations can satisfy the ordering check by coincidence, and the bytes between
+    /// them are not actually part of either input slice.
+    unsafe fn try_rejoin_bridge<'r>(&'r self, other: &'r Self) -> Option<&'r Self>;
 }
 
 impl<T> SliceExt for [T] {
     fn rejoin<'r>(&'r self, other: &'r [T]) -> &'r [T] {
-        self.try_rejoin(other).expect("the input slices must be adjacent in memory")
+        match self.checked_rejoin(other) {
+            Ok(joined) => joined,
+            Err(e) => panic!("the input slices must be adjacent in memory: {e}"),
+        }
     }
 
     fn rejoin_mut<'r>(&'r mut self, other: &'r mut [T]) -> &'r mut [T] {
-        self.try_rejoin_mut(other).expect("the input slices must be adjacent in memory")
+        match self.checked_rejoin_mut(other) {
+            Ok(joined) => joined,
+            Err(e) => panic!("the input slices must be adjacent in memory: {e}"),
+        }
     }
 
     fn try_rejoin<'r>(&'r self, other: &'r [T]) -> Option<&'r [T]> {
+        self.checked_rejoin(other).ok()
+    }
+
+    fn try_rejoin_mut<'r>(&'r mut self, other: &'r mut [T]) -> Option<&'r mut [T]> {
+        self.checked_rejoin_mut(other).ok()
+    }
+
+    fn checked_rejoin<'r>(&'r self, other: &'r [T]) -> Result<&'r [T], RejoinError> {
+        if core::mem::size_of::<T>() == 0 {
+            return Err(RejoinError::Disjoint);
+        }
+        let self_start = self.as_ptr() as usize;
+        let self_end = self[self.len()..].as_ptr() as usize;
+        let other_start = other.as_ptr() as usize;
+        if other_start < self_start {
+            return Err(RejoinError::WrongOrder);
+        }
+        if let Some(bytes) = other_start.checked_sub(self_end) {
+            return if bytes == 0 {
+                Ok(unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len() + other.len()) })
+            } else {
+                Err(RejoinError::Gap { bytes })
+            };
+        }
+        Err(RejoinError::Overlap { bytes: self_end - other_start })
+    }
+
+    fn checked_rejoin_mut<'r>(&'r mut self, other: &'r mut [T]) -> Result<&'r mut [T], RejoinError> {
+        if core::mem::size_of::<T>() == 0 {
+            return Err(RejoinError::Disjoint);
+        }
+        let self_len = self.len();
+        let self_start = self.as_mut_ptr() as usize;
+        let self_end = self[self_len..].as_mut_ptr() as usize;
+        let other_start = other.as_mut_ptr() as usize;
+        if other_start < self_start {
+            return Err(RejoinError::WrongOrder);
+        }
+        if let Some(bytes) = other_start.checked_sub(self_end) {
+            return if bytes == 0 {
+                Ok(unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len() + other.len()) })
+            } else {
+                Err(RejoinError::Gap { bytes })
+            };
+        }
+        Err(RejoinError::Overlap { bytes: self_end - other_start })
+    }
+
+    fn rejoin_all<'r, I: IntoIterator<Item = &'r [T]>>(iter: I) -> &'r [T]
+    where
+        [T]: 'r,
+    {
+        <[T] as SliceExt>::try_rejoin_all(iter).expect("the input slices must be a non-empty, adjacent run")
+    }
+
+    fn rejoin_all_mut<'r, I: IntoIterator<Item = &'r mut [T]>>(iter: I) -> &'r mut [T]
+    where
+        [T]: 'r,
+    {
+        <[T] as SliceExt>::try_rejoin_all_mut(iter).expect("the input slices must be a non-empty, adjacent run")
+    }
+
+    fn try_rejoin_all<'r, I: IntoIterator<Item = &'r [T]>>(iter: I) -> Option<&'r [T]>
+    where
+        [T]: 'r,
+    {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        iter.try_fold(first, |acc, piece| acc.try_rejoin(piece))
+    }
+
+    fn try_rejoin_all_mut<'r, I: IntoIterator<Item = &'r mut [T]>>(iter: I) -> Option<&'r mut [T]>
+    where
+        [T]: 'r,
+    {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        iter.try_fold(first, |acc, piece| acc.try_rejoin_mut(piece))
+    }
+
+    fn rejoin_either<'r>(&'r self, other: &'r [T]) -> &'r [T] {
+        self.try_rejoin_either(other).expect("the input slices must be adjacent in memory, in either order")
+    }
+
+    fn rejoin_either_mut<'r>(&'r mut self, other: &'r mut [T]) -> &'r mut [T] {
+        self.try_rejoin_either_mut(other).expect("the input slices must be adjacent in memory, in either order")
+    }
+
+    fn try_rejoin_either<'r>(&'r self, other: &'r [T]) -> Option<&'r [T]> {
+        if core::mem::size_of::<T>() == 0 {
+            return None;
+        }
         let self_len = self.len();
         let self_end = self[self_len..].as_ptr();
         if core::ptr::eq(self_end, other.as_ptr()) {
-            Some(unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len() + other.len()) })
-        } else {
-            None
+            return Some(unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len() + other.len()) });
         }
+        let other_len = other.len();
+        let other_end = other[other_len..].as_ptr();
+        if core::ptr::eq(other_end, self.as_ptr()) {
+            return Some(unsafe { core::slice::from_raw_parts(other.as_ptr(), self.len() + other.len()) });
+        }
+        None
     }
 
-    fn try_rejoin_mut<'r>(&'r mut self, other: &'r mut [T]) -> Option<&'r mut [T]> {
+    fn try_rejoin_either_mut<'r>(&'r mut self, other: &'r mut [T]) -> Option<&'r mut [T]> {
+        if core::mem::size_of::<T>() == 0 {
+            return None;
+        }
         let self_len = self.len();
         let self_end = self[self_len..].as_mut_ptr();
         if core::ptr::eq(self_end, other.as_mut_ptr()) {
-            Some(unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len() + other.len()) })
-        } else {
-            None
+            return Some(unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len() + other.len()) });
+        }
+        let other_len = other.len();
+        let other_end = other[other_len..].as_mut_ptr();
+        if core::ptr::eq(other_end, self.as_mut_ptr()) {
+            return Some(unsafe { core::slice::from_raw_parts_mut(other.as_mut_ptr(), self.len() + other.len()) });
         }
+        None
+    }
+
+    unsafe fn rejoin_overlapping<'r>(&'r self, other: &'r [T]) -> &'r [T] {
+        unsafe { self.try_rejoin_overlapping(other) }
+            .expect("other must start at or after self, with no gap between them")
+    }
+
+    unsafe fn try_rejoin_overlapping<'r>(&'r self, other: &'r [T]) -> Option<&'r [T]> {
+        if core::mem::size_of::<T>() == 0 {
+            return None;
+        }
+        let self_start = self.as_ptr() as usize;
+        let self_end = self[self.len()..].as_ptr() as usize;
+        let other_start = other.as_ptr() as usize;
+        let other_end = other[other.len()..].as_ptr() as usize;
+
+        if other_start < self_start || other_start > self_end {
+            return None;
+        }
+
+        let end = self_end.max(other_end);
+        let len = (end - self_start) / core::mem::size_of::<T>();
+        Some(unsafe { core::slice::from_raw_parts(self.as_ptr(), len) })
+    }
+
+    unsafe fn rejoin_bridge<'r>(&'r self, other: &'r [T]) -> &'r [T] {
+        unsafe { self.try_rejoin_bridge(other) }.expect("other must start at or after the end of self")
+    }
+
+    unsafe fn try_rejoin_bridge<'r>(&'r self, other: &'r [T]) -> Option<&'r [T]> {
+        if core::mem::size_of::<T>() == 0 {
+            return None;
+        }
+        let self_start = self.as_ptr() as usize;
+        let self_end = self[self.len()..].as_ptr() as usize;
+        let other_start = other.as_ptr() as usize;
+        let other_end = other[other.len()..].as_ptr() as usize;
+
+        if other_start < self_end {
+            return None;
+        }
+
+        let len = (other_end - self_start) / core::mem::size_of::<T>();
+        Some(unsafe { core::slice::from_raw_parts(self.as_ptr(), len) })
     }
 }
 
@@ -98,15 +378,128 @@ pub trait StrExt {
     /// Joins two string slices that are adjacent in memory into one string slice.
     /// Returns None in the case the slices aren't adjacent.
     fn try_rejoin<'r>(&'r self, other: &'r str) -> Option<&'r str>;
+
+    /// Joins two string slices that are adjacent in memory into one string slice.
+    /// Returns a [`RejoinError`] describing why the slices aren't adjacent.
+    fn checked_rejoin<'r>(&'r self, other: &'r str) -> Result<&'r str, RejoinError>;
+
+    /// Joins an iterator of string slices that are adjacent in memory, in order, into one string slice.
+    /// # Panics
+    /// Panics in the case the iterator is empty, or any two consecutive pieces aren't adjacent.
+    fn rejoin_all<'r, I: IntoIterator<Item = &'r str>>(iter: I) -> &'r str;
+
+    /// Joins an iterator of string slices that are adjacent in memory, in order, into one string slice.
+    /// Returns None in the case the iterator is empty, or any two consecutive pieces aren't adjacent.
+    fn try_rejoin_all<'r, I: IntoIterator<Item = &'r str>>(iter: I) -> Option<&'r str>;
+
+    /// Joins two string slices that are adjacent in memory into one string slice, regardless of
+    /// which one comes first.
+    /// # Panics
+    /// Panics in the case neither ordering of the slices is adjacent.
+    fn rejoin_either<'r>(&'r self, other: &'r str) -> &'r str;
+
+    /// Joins two string slices that are adjacent in memory into one string slice, regardless of
+    /// which one comes first. Returns None in the case neither ordering of the slices is adjacent.
+    fn try_rejoin_either<'r>(&'r self, other: &'r str) -> Option<&'r str>;
+
+    /// Joins two string slices that are adjacent in memory, or that overlap, into their covering
+    /// string slice. `other` must start at or after `self` and leave no gap.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object. This can't be checked from
+    /// the slices alone: the adjacency check here only orders pointer addresses, and two slices
+    /// from unrelated allocations can satisfy that ordering by coincidence.
+    /// # Panics
+    /// Panics in the case `other` starts before `self`, there is a gap between them, or the
+    /// overlap doesn't fall on a char boundary.
+    unsafe fn rejoin_overlapping<'r>(&'r self, other: &'r str) -> &'r str;
+
+    /// Joins two string slices that are adjacent in memory, or that overlap, into their covering
+    /// string slice. Returns None in the case `other` starts before `self`, there is a gap
+    /// between them, or the overlap doesn't fall on a char boundary.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object. This can't be checked from
+    /// the slices alone: the adjacency check here only orders pointer addresses, and two slices
+    /// from unrelated allocations can satisfy that ordering by coincidence.
+    unsafe fn try_rejoin_overlapping<'r>(&'r self, other: &'r str) -> Option<&'r str>;
+
+    /// Recovers the full span `self.start..other.end` of two non-overlapping string slices from
+    /// the same parent string, including any untouched middle region between them.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object, with `other` starting at or
+    /// after the end of `self`. This can't be checked from the slices alone: two slices from
+    /// unrelated allocations can satisfy the ordering check by coincidence, and the bytes between
+    /// them are not actually part of either input slice.
+    /// # Panics
+    /// Panics in the case `other` starts before the end of `self`.
+    unsafe fn rejoin_bridge<'r>(&'r self, other: &'r str) -> &'r str;
+
+    /// Recovers the full span `self.start..other.end` of two non-overlapping string slices from
+    /// the same parent string, including any untouched middle region between them.
+    /// Returns None in the case `other` starts before the end of `self`.
+    /// # Safety
+    /// `self` and `other` must point into the same allocated object, with `other` starting at or
+    /// after the end of `self`. This can't be checked from the slices alone: two slices from
+    /// unrelated allocations can satisfy the ordering check by coincidence, and the bytes between
+    /// them are not actually part of either input slice.
+    unsafe fn try_rejoin_bridge<'r>(&'r self, other: &'r str) -> Option<&'r str>;
 }
 
 impl StrExt for str {
     fn rejoin<'r>(&'r self, other: &'r str) -> &'r str {
-        self.try_rejoin(other).expect("the input string slices must be adjacent in memory")
+        match self.checked_rejoin(other) {
+            Ok(joined) => joined,
+            Err(e) => panic!("the input string slices must be adjacent in memory: {e}"),
+        }
     }
 
     fn try_rejoin<'r>(&'r self, other: &'r str) -> Option<&'r str> {
-        self.as_bytes().try_rejoin(other.as_bytes()).map(|s| unsafe { core::str::from_utf8_unchecked(s) })
+        self.checked_rejoin(other).ok()
+    }
+
+    fn checked_rejoin<'r>(&'r self, other: &'r str) -> Result<&'r str, RejoinError> {
+        self.as_bytes()
+            .checked_rejoin(other.as_bytes())
+            .map(|s| unsafe { core::str::from_utf8_unchecked(s) })
+    }
+
+    fn rejoin_all<'r, I: IntoIterator<Item = &'r str>>(iter: I) -> &'r str {
+        <str as StrExt>::try_rejoin_all(iter).expect("the input string slices must be a non-empty, adjacent run")
+    }
+
+    fn try_rejoin_all<'r, I: IntoIterator<Item = &'r str>>(iter: I) -> Option<&'r str> {
+        let bytes = <[u8] as SliceExt>::try_rejoin_all(iter.into_iter().map(str::as_bytes))?;
+        Some(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    fn rejoin_either<'r>(&'r self, other: &'r str) -> &'r str {
+        self.try_rejoin_either(other).expect("the input string slices must be adjacent in memory, in either order")
+    }
+
+    fn try_rejoin_either<'r>(&'r self, other: &'r str) -> Option<&'r str> {
+        self.as_bytes().try_rejoin_either(other.as_bytes()).map(|s| unsafe { core::str::from_utf8_unchecked(s) })
+    }
+
+    unsafe fn rejoin_overlapping<'r>(&'r self, other: &'r str) -> &'r str {
+        unsafe { self.try_rejoin_overlapping(other) }
+            .expect("other must start at or after self, with no gap between them, on a char boundary")
+    }
+
+    unsafe fn try_rejoin_overlapping<'r>(&'r self, other: &'r str) -> Option<&'r str> {
+        let offset = (other.as_ptr() as usize).checked_sub(self.as_ptr() as usize)?;
+        if offset <= self.len() && !self.is_char_boundary(offset) {
+            return None;
+        }
+        unsafe { self.as_bytes().try_rejoin_overlapping(other.as_bytes()) }
+            .map(|s| unsafe { core::str::from_utf8_unchecked(s) })
+    }
+
+    unsafe fn rejoin_bridge<'r>(&'r self, other: &'r str) -> &'r str {
+        unsafe { self.try_rejoin_bridge(other) }.expect("other must start at or after the end of self")
+    }
+
+    unsafe fn try_rejoin_bridge<'r>(&'r self, other: &'r str) -> Option<&'r str> {
+        unsafe { self.as_bytes().try_rejoin_bridge(other.as_bytes()) }
+            .map(|s| unsafe { core::str::from_utf8_unchecked(s) })
     }
 }
 
@@ -237,6 +630,251 @@ fn test_try_rejoin_mut() {
     assert_eq!(b.try_rejoin_mut(a), None);
 }
 
+#[test]
+fn test_checked_rejoin() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    assert_eq!(slice[..3].checked_rejoin(&slice[3..]), Ok(slice));
+    assert_eq!(slice[..0].checked_rejoin(&slice[0..]), Ok(slice));
+
+    let elem = core::mem::size_of::<i32>();
+    assert_eq!(slice[..2].checked_rejoin(&slice[3..]), Err(RejoinError::Gap { bytes: elem }));
+    assert_eq!(slice[..3].checked_rejoin(&slice[1..]), Err(RejoinError::Overlap { bytes: 2 * elem }));
+    assert_eq!(slice[3..].checked_rejoin(&slice[..3]), Err(RejoinError::WrongOrder));
+}
+
+#[test]
+fn test_checked_rejoin_mut() {
+    let slice = &mut [0, 1, 2, 3, 4, 5, 6][..];
+
+    let (a, b) = slice.split_at_mut(3);
+    a.checked_rejoin_mut(b).unwrap().copy_from_slice(&[14, 15, 16, 17, 18, 19, 20][..]);
+    assert_eq!(slice, &[14, 15, 16, 17, 18, 19, 20][..]);
+
+    let (a, b) = slice.split_at_mut(3);
+    let (_, b) = b.split_at_mut(1);
+    assert!(matches!(a.checked_rejoin_mut(b), Err(RejoinError::Gap { .. })));
+
+    let (a, b) = slice.split_at_mut(3);
+    assert!(matches!(b.checked_rejoin_mut(a), Err(RejoinError::WrongOrder)));
+}
+
+#[test]
+fn test_rejoin_all() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    assert_eq!(SliceExt::rejoin_all([&slice[..3], &slice[3..]]), slice);
+    assert_eq!(SliceExt::rejoin_all([&slice[..2], &slice[2..4], &slice[4..]]), slice);
+    assert_eq!(SliceExt::rejoin_all([&slice[..0]]), &slice[..0]);
+}
+
+#[test]
+#[should_panic]
+fn test_rejoin_all_empty() {
+    let empty: [&[i32]; 0] = [];
+    SliceExt::rejoin_all(empty);
+}
+
+#[test]
+#[should_panic]
+fn test_rejoin_all_nogaps() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    // Don't allow gaps between pieces
+    SliceExt::rejoin_all([&slice[..2], &slice[3..]]);
+}
+
+#[test]
+fn test_rejoin_all_mut() {
+    let slice = &mut [0, 1, 2, 3, 4, 5, 6][..];
+
+    let (a, rest) = slice.split_at_mut(2);
+    let (b, c) = rest.split_at_mut(2);
+    SliceExt::rejoin_all_mut([a, b, c]).copy_from_slice(&[14, 15, 16, 17, 18, 19, 20][..]);
+    assert_eq!(slice, &[14, 15, 16, 17, 18, 19, 20][..]);
+}
+
+#[test]
+fn test_try_rejoin_all() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    assert_eq!(SliceExt::try_rejoin_all([&slice[..3], &slice[3..]]), Some(slice));
+    assert_eq!(SliceExt::try_rejoin_all([&slice[..2], &slice[2..4], &slice[4..]]), Some(slice));
+
+    let empty: [&[i32]; 0] = [];
+    assert_eq!(SliceExt::try_rejoin_all(empty), None);
+    assert_eq!(SliceExt::try_rejoin_all([&slice[..2], &slice[3..]]), None);
+    assert_eq!(SliceExt::try_rejoin_all([&slice[3..], &slice[..3]]), None);
+}
+
+#[test]
+fn test_str_rejoin_all() {
+    let slice = &"abcdefg"[..];
+
+    assert_eq!(<str as StrExt>::rejoin_all([&slice[..3], &slice[3..]]), slice);
+    assert_eq!(<str as StrExt>::rejoin_all([&slice[..2], &slice[2..4], &slice[4..]]), slice);
+}
+
+#[test]
+fn test_str_try_rejoin_all() {
+    let slice = &"abcdefg"[..];
+
+    assert_eq!(<str as StrExt>::try_rejoin_all([&slice[..3], &slice[3..]]), Some(slice));
+
+    let empty: [&str; 0] = [];
+    assert_eq!(<str as StrExt>::try_rejoin_all(empty), None);
+    assert_eq!(<str as StrExt>::try_rejoin_all([&slice[..2], &slice[3..]]), None);
+}
+
+#[test]
+fn test_rejoin_either() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    assert_eq!(slice[..3].rejoin_either(&slice[3..]), slice);
+    assert_eq!(slice[3..].rejoin_either(&slice[..3]), slice);
+    assert_eq!(slice[..0].rejoin_either(&slice[0..]), slice);
+}
+
+#[test]
+#[should_panic]
+fn test_rejoin_either_nogaps() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    // Don't allow gaps between slices, in either order
+    slice[..3].rejoin_either(&slice[4..]);
+}
+
+#[test]
+fn test_rejoin_either_mut() {
+    let slice = &mut [0, 1, 2, 3, 4, 5, 6][..];
+
+    let (a, b) = slice.split_at_mut(3);
+    b.rejoin_either_mut(a).copy_from_slice(&[14, 15, 16, 17, 18, 19, 20][..]);
+    assert_eq!(slice, &[14, 15, 16, 17, 18, 19, 20][..]);
+}
+
+#[test]
+fn test_try_rejoin_either() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    assert_eq!(slice[..3].try_rejoin_either(&slice[3..]), Some(slice));
+    assert_eq!(slice[3..].try_rejoin_either(&slice[..3]), Some(slice));
+
+    assert_eq!(slice[..3].try_rejoin_either(&slice[4..]), None);
+}
+
+#[test]
+fn test_try_rejoin_either_zst() {
+    let slice = &[(), (), (), ()][..];
+
+    // Every zero-sized slice is indistinguishable by pointer alone; refuse to guess adjacency.
+    assert_eq!(slice[..1].try_rejoin_either(&slice[2..]), None);
+}
+
+#[test]
+fn test_try_rejoin_either_mut() {
+    let slice = &mut [0, 1, 2, 3, 4, 5, 6][..];
+
+    let (a, b) = slice.split_at_mut(3);
+    b.try_rejoin_either_mut(a).as_mut().map(|s| s.copy_from_slice(&[14, 15, 16, 17, 18, 19, 20][..]));
+    assert_eq!(slice, &[14, 15, 16, 17, 18, 19, 20][..]);
+
+    let (a, b) = slice.split_at_mut(3);
+    let (_, b) = b.split_at_mut(1);
+    assert_eq!(a.try_rejoin_either_mut(b), None);
+}
+
+#[test]
+fn test_str_rejoin_either() {
+    let slice = &"abcdefg"[..];
+
+    assert_eq!(slice[..3].rejoin_either(&slice[3..]), slice);
+    assert_eq!(slice[3..].rejoin_either(&slice[..3]), slice);
+}
+
+#[test]
+fn test_str_try_rejoin_either() {
+    let slice = &"abcdefg"[..];
+
+    assert_eq!(slice[..3].try_rejoin_either(&slice[3..]), Some(slice));
+    assert_eq!(slice[3..].try_rejoin_either(&slice[..3]), Some(slice));
+    assert_eq!(slice[..3].try_rejoin_either(&slice[4..]), None);
+}
+
+#[test]
+fn test_rejoin_overlapping() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    unsafe {
+        assert_eq!(slice[0..3].rejoin_overlapping(&slice[1..4]), &slice[0..4]);
+        assert_eq!(slice[0..3].rejoin_overlapping(&slice[3..6]), &slice[0..6]);
+        // Adjacent, non-overlapping slices are also accepted.
+        assert_eq!(slice[..3].rejoin_overlapping(&slice[3..]), slice);
+        // A fully contained slice doesn't extend the union.
+        assert_eq!(slice[..5].rejoin_overlapping(&slice[1..3]), &slice[..5]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_rejoin_overlapping_wrongorder() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    // Don't allow other to start before self.
+    unsafe { slice[3..].rejoin_overlapping(&slice[..4]) };
+}
+
+#[test]
+#[should_panic]
+fn test_rejoin_overlapping_gap() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    // Don't allow a gap between self and other.
+    unsafe { slice[..2].rejoin_overlapping(&slice[3..]) };
+}
+
+#[test]
+fn test_try_rejoin_overlapping() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    unsafe {
+        assert_eq!(slice[0..3].try_rejoin_overlapping(&slice[1..4]), Some(&slice[0..4]));
+        assert_eq!(slice[3..].try_rejoin_overlapping(&slice[..4]), None);
+        assert_eq!(slice[..2].try_rejoin_overlapping(&slice[3..]), None);
+    }
+}
+
+#[test]
+fn test_try_rejoin_overlapping_zst() {
+    let slice = &[(), (), (), ()][..];
+
+    // Zero-sized elements make the gap/overlap math degenerate; bail out instead of dividing by zero.
+    unsafe {
+        assert_eq!(slice[..2].try_rejoin_overlapping(&slice[1..]), None);
+    }
+}
+
+#[test]
+fn test_str_rejoin_overlapping() {
+    let slice = &"abcdefg"[..];
+
+    unsafe {
+        assert_eq!(slice[..4].rejoin_overlapping(&slice[2..5]), &slice[..5]);
+        assert_eq!(slice[..3].rejoin_overlapping(&slice[3..]), slice);
+    }
+}
+
+#[test]
+fn test_str_try_rejoin_overlapping() {
+    let slice = &"abcdefg"[..];
+
+    unsafe {
+        assert_eq!(slice[..4].try_rejoin_overlapping(&slice[2..5]), Some(&slice[..5]));
+        assert_eq!(slice[3..].try_rejoin_overlapping(&slice[..4]), None);
+        assert_eq!(slice[..2].try_rejoin_overlapping(&slice[3..]), None);
+    }
+}
+
 #[test]
 fn test_str_rejoin() {
     let slice = &"abcdefg"[..];
@@ -279,3 +917,78 @@ fn test_str_try_rejoin() {
     assert_eq!(slice[..3].try_rejoin(&slice[4..]), None);
     assert_eq!(slice[3..].try_rejoin(&slice[3..]), None);
 }
+
+
+#[test]
+fn test_str_checked_rejoin() {
+    let slice = &"abcdefg"[..];
+
+    assert_eq!(slice[..3].checked_rejoin(&slice[3..]), Ok(slice));
+    assert_eq!(slice[..2].checked_rejoin(&slice[3..]), Err(RejoinError::Gap { bytes: 1 }));
+    assert_eq!(slice[..3].checked_rejoin(&slice[1..]), Err(RejoinError::Overlap { bytes: 2 }));
+    assert_eq!(slice[3..].checked_rejoin(&slice[..3]), Err(RejoinError::WrongOrder));
+}
+
+
+#[test]
+fn test_rejoin_bridge() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    unsafe {
+        assert_eq!(slice[..2].rejoin_bridge(&slice[4..]), slice);
+        assert_eq!(slice[..3].rejoin_bridge(&slice[3..]), slice);
+        assert_eq!(slice[..0].rejoin_bridge(&slice[0..]), slice);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_rejoin_bridge_wrongorder() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    // Don't allow other to start before the end of self.
+    unsafe { slice[4..].rejoin_bridge(&slice[..2]) };
+}
+
+#[test]
+fn test_try_rejoin_bridge() {
+    let slice = &[0, 1, 2, 3, 4, 5, 6][..];
+
+    unsafe {
+        assert_eq!(slice[..2].try_rejoin_bridge(&slice[4..]), Some(slice));
+        assert_eq!(slice[..3].try_rejoin_bridge(&slice[3..]), Some(slice));
+        assert_eq!(slice[4..].try_rejoin_bridge(&slice[..2]), None);
+        // Overlapping input is rejected; use rejoin_overlapping for that.
+        assert_eq!(slice[..3].try_rejoin_bridge(&slice[1..]), None);
+    }
+}
+
+#[test]
+fn test_try_rejoin_bridge_zst() {
+    let slice = &[(), (), (), ()][..];
+
+    // Zero-sized elements make the gap math degenerate; bail out instead of dividing by zero.
+    unsafe {
+        assert_eq!(slice[..1].try_rejoin_bridge(&slice[2..]), None);
+    }
+}
+
+#[test]
+fn test_str_rejoin_bridge() {
+    let slice = &"abcdefg"[..];
+
+    unsafe {
+        assert_eq!(slice[..2].rejoin_bridge(&slice[4..]), slice);
+        assert_eq!(slice[..3].rejoin_bridge(&slice[3..]), slice);
+    }
+}
+
+#[test]
+fn test_str_try_rejoin_bridge() {
+    let slice = &"abcdefg"[..];
+
+    unsafe {
+        assert_eq!(slice[..2].try_rejoin_bridge(&slice[4..]), Some(slice));
+        assert_eq!(slice[4..].try_rejoin_bridge(&slice[..2]), None);
+    }
+}